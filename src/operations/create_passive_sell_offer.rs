@@ -0,0 +1,257 @@
+use crate::amount::{Price, Stroops};
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::manage_sell_offer::{price_from_f64, price_from_xdr};
+use crate::operations::Operation;
+use crate::xdr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePassiveSellOfferOperation {
+    source_account: Option<MuxedAccount>,
+    selling: Asset,
+    buying: Asset,
+    amount: Stroops,
+    price: Price,
+}
+
+#[derive(Debug)]
+pub struct CreatePassiveSellOfferOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    selling: Option<Asset>,
+    buying: Option<Asset>,
+    amount: Option<Stroops>,
+    price: Option<f64>,
+}
+
+impl CreatePassiveSellOfferOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves a reference to the operation source account.
+    pub fn source_account_mut(&mut self) -> &mut Option<MuxedAccount> {
+        &mut self.source_account
+    }
+
+    /// Retrieves the asset being sold.
+    pub fn selling(&self) -> &Asset {
+        &self.selling
+    }
+
+    /// Retrieves a mutable reference to the asset being sold.
+    pub fn selling_mut(&mut self) -> &mut Asset {
+        &mut self.selling
+    }
+
+    /// Retrieves the asset being bought.
+    pub fn buying(&self) -> &Asset {
+        &self.buying
+    }
+
+    /// Retrieves a mutable reference to the asset being bought.
+    pub fn buying_mut(&mut self) -> &mut Asset {
+        &mut self.buying
+    }
+
+    /// Retrieves the amount of selling asset being offered.
+    pub fn amount(&self) -> &Stroops {
+        &self.amount
+    }
+
+    /// Retrieves a mutable reference to the amount.
+    pub fn amount_mut(&mut self) -> &mut Stroops {
+        &mut self.amount
+    }
+
+    /// Retrieves the price of 1 unit of selling in terms of buying.
+    pub fn price(&self) -> &Price {
+        &self.price
+    }
+
+    /// Retrieves a mutable reference to the price.
+    pub fn price_mut(&mut self) -> &mut Price {
+        &mut self.price
+    }
+
+    /// Returns the xdr operation body.
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let selling = self.selling.to_xdr_asset()?;
+        let buying = self.buying.to_xdr_asset()?;
+        let amount = xdr::Int64::new(self.amount.to_i64());
+        let price = xdr::Price {
+            n: xdr::Int32::new(self.price.n()),
+            d: xdr::Int32::new(self.price.d()),
+        };
+        let inner = xdr::CreatePassiveSellOfferOp {
+            selling,
+            buying,
+            amount,
+            price,
+        };
+        Ok(xdr::OperationBody::CreatePassiveSellOffer(inner))
+    }
+
+    /// Creates from the xdr operation body.
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::CreatePassiveSellOfferOp,
+    ) -> Result<CreatePassiveSellOfferOperation> {
+        let selling = Asset::from_xdr_asset(&x.selling)?;
+        let buying = Asset::from_xdr_asset(&x.buying)?;
+        let amount = Stroops::new(x.amount.value)?;
+        let price = price_from_xdr(&x.price)?;
+        Ok(CreatePassiveSellOfferOperation {
+            source_account,
+            selling,
+            buying,
+            amount,
+            price,
+        })
+    }
+}
+
+impl CreatePassiveSellOfferOperationBuilder {
+    pub fn new() -> CreatePassiveSellOfferOperationBuilder {
+        CreatePassiveSellOfferOperationBuilder {
+            source_account: None,
+            selling: None,
+            buying: None,
+            amount: None,
+            price: None,
+        }
+    }
+
+    pub fn with_source_account<S>(mut self, source: S) -> CreatePassiveSellOfferOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    pub fn with_selling(mut self, selling: Asset) -> CreatePassiveSellOfferOperationBuilder {
+        self.selling = Some(selling);
+        self
+    }
+
+    pub fn with_buying(mut self, buying: Asset) -> CreatePassiveSellOfferOperationBuilder {
+        self.buying = Some(buying);
+        self
+    }
+
+    pub fn with_amount(mut self, amount: Stroops) -> CreatePassiveSellOfferOperationBuilder {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn with_price(mut self, price: f64) -> CreatePassiveSellOfferOperationBuilder {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let selling = self.selling.ok_or_else(|| {
+            Error::InvalidOperation("missing create passive sell offer selling asset".to_string())
+        })?;
+
+        let buying = self.buying.ok_or_else(|| {
+            Error::InvalidOperation("missing create passive sell offer buying asset".to_string())
+        })?;
+
+        let amount = self.amount.unwrap_or(Stroops::new(0)?);
+
+        let price = self.price.ok_or_else(|| {
+            Error::InvalidOperation("missing create passive sell offer price".to_string())
+        })?;
+        let price = price_from_f64(price)?;
+
+        Ok(Operation::CreatePassiveSellOffer(
+            CreatePassiveSellOfferOperation {
+                source_account: self.source_account,
+                selling,
+                buying,
+                amount,
+                price,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CreatePassiveSellOfferOperation, CreatePassiveSellOfferOperationBuilder};
+    use crate::amount::Stroops;
+    use crate::asset::Asset;
+    use crate::operations::Operation;
+    use crate::xdr;
+
+    #[test]
+    fn test_create_passive_sell_offer_xdr_operation_body_round_trip() {
+        let op = CreatePassiveSellOfferOperationBuilder::new()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(1.5)
+            .build()
+            .unwrap();
+
+        let inner = match op {
+            Operation::CreatePassiveSellOffer(inner) => inner,
+            _ => panic!("expected CreatePassiveSellOffer operation"),
+        };
+
+        let body = inner.to_xdr_operation_body().unwrap();
+        let decoded = match body {
+            xdr::OperationBody::CreatePassiveSellOffer(ref x) => {
+                CreatePassiveSellOfferOperation::from_xdr_operation_body(None, x).unwrap()
+            }
+            _ => panic!("expected CreatePassiveSellOffer operation body"),
+        };
+
+        assert_eq!(inner, decoded);
+    }
+
+    #[test]
+    fn test_create_passive_sell_offer_rejects_zero_denominator_price_on_decode() {
+        let price = xdr::Price {
+            n: xdr::Int32::new(1),
+            d: xdr::Int32::new(0),
+        };
+        let x = xdr::CreatePassiveSellOfferOp {
+            selling: Asset::native().to_xdr_asset().unwrap(),
+            buying: Asset::native().to_xdr_asset().unwrap(),
+            amount: xdr::Int64::new(100),
+            price,
+        };
+        assert!(CreatePassiveSellOfferOperation::from_xdr_operation_body(None, &x).is_err());
+    }
+
+    #[test]
+    fn test_create_passive_sell_offer_requires_selling_and_buying() {
+        let err = Operation::new_create_passive_sell_offer()
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(1.0)
+            .build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_create_passive_sell_offer_builds() {
+        let op = Operation::new_create_passive_sell_offer()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(1.5)
+            .build()
+            .unwrap();
+
+        match op {
+            Operation::CreatePassiveSellOffer(inner) => {
+                assert_eq!(*inner.amount(), Stroops::new(100).unwrap())
+            }
+            _ => panic!("expected CreatePassiveSellOffer operation"),
+        }
+    }
+}
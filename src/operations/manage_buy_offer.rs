@@ -0,0 +1,280 @@
+use crate::amount::{Price, Stroops};
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::manage_sell_offer::{price_from_f64, price_from_xdr};
+use crate::operations::Operation;
+use crate::xdr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManageBuyOfferOperation {
+    source_account: Option<MuxedAccount>,
+    selling: Asset,
+    buying: Asset,
+    buy_amount: Stroops,
+    price: Price,
+    offer_id: i64,
+}
+
+#[derive(Debug)]
+pub struct ManageBuyOfferOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    selling: Option<Asset>,
+    buying: Option<Asset>,
+    amount: Option<Stroops>,
+    price: Option<f64>,
+    offer_id: Option<i64>,
+}
+
+impl ManageBuyOfferOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves a reference to the operation source account.
+    pub fn source_account_mut(&mut self) -> &mut Option<MuxedAccount> {
+        &mut self.source_account
+    }
+
+    /// Retrieves the asset being sold.
+    pub fn selling(&self) -> &Asset {
+        &self.selling
+    }
+
+    /// Retrieves a mutable reference to the asset being sold.
+    pub fn selling_mut(&mut self) -> &mut Asset {
+        &mut self.selling
+    }
+
+    /// Retrieves the asset being bought.
+    pub fn buying(&self) -> &Asset {
+        &self.buying
+    }
+
+    /// Retrieves a mutable reference to the asset being bought.
+    pub fn buying_mut(&mut self) -> &mut Asset {
+        &mut self.buying
+    }
+
+    /// Retrieves the amount of buying asset being sought (0 deletes the offer).
+    pub fn buy_amount(&self) -> &Stroops {
+        &self.buy_amount
+    }
+
+    /// Retrieves a mutable reference to the buy amount.
+    pub fn buy_amount_mut(&mut self) -> &mut Stroops {
+        &mut self.buy_amount
+    }
+
+    /// Retrieves the price of 1 unit of buying in terms of selling.
+    pub fn price(&self) -> &Price {
+        &self.price
+    }
+
+    /// Retrieves a mutable reference to the price.
+    pub fn price_mut(&mut self) -> &mut Price {
+        &mut self.price
+    }
+
+    /// Retrieves the offer id (0 creates a new offer).
+    pub fn offer_id(&self) -> &i64 {
+        &self.offer_id
+    }
+
+    /// Retrieves a mutable reference to the offer id.
+    pub fn offer_id_mut(&mut self) -> &mut i64 {
+        &mut self.offer_id
+    }
+
+    /// Returns the xdr operation body.
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let selling = self.selling.to_xdr_asset()?;
+        let buying = self.buying.to_xdr_asset()?;
+        let buy_amount = xdr::Int64::new(self.buy_amount.to_i64());
+        let price = xdr::Price {
+            n: xdr::Int32::new(self.price.n()),
+            d: xdr::Int32::new(self.price.d()),
+        };
+        let offer_id = xdr::Int64::new(self.offer_id);
+        let inner = xdr::ManageBuyOfferOp {
+            selling,
+            buying,
+            buy_amount,
+            price,
+            offer_id,
+        };
+        Ok(xdr::OperationBody::ManageBuyOffer(inner))
+    }
+
+    /// Creates from the xdr operation body.
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::ManageBuyOfferOp,
+    ) -> Result<ManageBuyOfferOperation> {
+        let selling = Asset::from_xdr_asset(&x.selling)?;
+        let buying = Asset::from_xdr_asset(&x.buying)?;
+        let buy_amount = Stroops::new(x.buy_amount.value)?;
+        let price = price_from_xdr(&x.price)?;
+        let offer_id = x.offer_id.value;
+        Ok(ManageBuyOfferOperation {
+            source_account,
+            selling,
+            buying,
+            buy_amount,
+            price,
+            offer_id,
+        })
+    }
+}
+
+impl ManageBuyOfferOperationBuilder {
+    pub fn new() -> ManageBuyOfferOperationBuilder {
+        ManageBuyOfferOperationBuilder {
+            source_account: None,
+            selling: None,
+            buying: None,
+            amount: None,
+            price: None,
+            offer_id: None,
+        }
+    }
+
+    pub fn with_source_account<S>(mut self, source: S) -> ManageBuyOfferOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    pub fn with_selling(mut self, selling: Asset) -> ManageBuyOfferOperationBuilder {
+        self.selling = Some(selling);
+        self
+    }
+
+    pub fn with_buying(mut self, buying: Asset) -> ManageBuyOfferOperationBuilder {
+        self.buying = Some(buying);
+        self
+    }
+
+    pub fn with_amount(mut self, amount: Stroops) -> ManageBuyOfferOperationBuilder {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn with_price(mut self, price: f64) -> ManageBuyOfferOperationBuilder {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn with_offer_id(mut self, offer_id: i64) -> ManageBuyOfferOperationBuilder {
+        self.offer_id = Some(offer_id);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let selling = self.selling.ok_or_else(|| {
+            Error::InvalidOperation("missing manage buy offer selling asset".to_string())
+        })?;
+
+        let buying = self.buying.ok_or_else(|| {
+            Error::InvalidOperation("missing manage buy offer buying asset".to_string())
+        })?;
+
+        let buy_amount = self.amount.unwrap_or(Stroops::new(0)?);
+
+        let price = self.price.ok_or_else(|| {
+            Error::InvalidOperation("missing manage buy offer price".to_string())
+        })?;
+        let price = price_from_f64(price)?;
+
+        let offer_id = self.offer_id.unwrap_or(0);
+
+        Ok(Operation::ManageBuyOffer(ManageBuyOfferOperation {
+            source_account: self.source_account,
+            selling,
+            buying,
+            buy_amount,
+            price,
+            offer_id,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ManageBuyOfferOperation, ManageBuyOfferOperationBuilder};
+    use crate::amount::Stroops;
+    use crate::asset::Asset;
+    use crate::operations::Operation;
+    use crate::xdr;
+
+    #[test]
+    fn test_manage_buy_offer_xdr_operation_body_round_trip() {
+        let op = ManageBuyOfferOperationBuilder::new()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(0.2)
+            .with_offer_id(3)
+            .build()
+            .unwrap();
+
+        let inner = match op {
+            Operation::ManageBuyOffer(inner) => inner,
+            _ => panic!("expected ManageBuyOffer operation"),
+        };
+
+        let body = inner.to_xdr_operation_body().unwrap();
+        let decoded = match body {
+            xdr::OperationBody::ManageBuyOffer(ref x) => {
+                ManageBuyOfferOperation::from_xdr_operation_body(None, x).unwrap()
+            }
+            _ => panic!("expected ManageBuyOffer operation body"),
+        };
+
+        assert_eq!(inner, decoded);
+    }
+
+    #[test]
+    fn test_manage_buy_offer_rejects_zero_denominator_price_on_decode() {
+        let price = xdr::Price {
+            n: xdr::Int32::new(1),
+            d: xdr::Int32::new(0),
+        };
+        let x = xdr::ManageBuyOfferOp {
+            selling: Asset::native().to_xdr_asset().unwrap(),
+            buying: Asset::native().to_xdr_asset().unwrap(),
+            buy_amount: xdr::Int64::new(100),
+            price,
+            offer_id: xdr::Int64::new(0),
+        };
+        assert!(ManageBuyOfferOperation::from_xdr_operation_body(None, &x).is_err());
+    }
+
+    #[test]
+    fn test_manage_buy_offer_defaults_offer_id_to_zero() {
+        let op = Operation::new_manage_buy_offer()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(0.2)
+            .build()
+            .unwrap();
+
+        match op {
+            Operation::ManageBuyOffer(inner) => assert_eq!(*inner.offer_id(), 0),
+            _ => panic!("expected ManageBuyOffer operation"),
+        }
+    }
+
+    #[test]
+    fn test_manage_buy_offer_requires_price() {
+        let err = Operation::new_manage_buy_offer()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .build();
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,382 @@
+use crate::amount::{Price, Stroops};
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// Finds the closest rational approximation `n/d` (with `d <= i32::MAX`) to a
+/// decimal price, via a continued-fraction expansion. Shared by the offer
+/// operations, which all encode price as a ratio of two `i32`s.
+pub(crate) fn price_from_f64(value: f64) -> Result<Price> {
+    if !value.is_finite() || value <= 0.0 {
+        return Err(Error::InvalidOperation(
+            "price must be a positive finite number".to_string(),
+        ));
+    }
+
+    let (mut h_prev2, mut h_prev1) = (0i64, 1i64);
+    let (mut k_prev2, mut k_prev1) = (1i64, 0i64);
+    let mut best: Option<(i64, i64)> = None;
+    let mut x = value;
+
+    loop {
+        let a = x.floor() as i64;
+        let h = a.checked_mul(h_prev1).and_then(|v| v.checked_add(h_prev2));
+        let k = a.checked_mul(k_prev1).and_then(|v| v.checked_add(k_prev2));
+
+        let (h, k) = match (h, k) {
+            (Some(h), Some(k)) if h <= i32::MAX as i64 && k > 0 && k <= i32::MAX as i64 => (h, k),
+            _ => break,
+        };
+
+        best = Some((h, k));
+        h_prev2 = h_prev1;
+        h_prev1 = h;
+        k_prev2 = k_prev1;
+        k_prev1 = k;
+
+        let frac = x - a as f64;
+        if frac.abs() < 1e-10 {
+            break;
+        }
+        x = 1.0 / frac;
+    }
+
+    let (n, d) = best.ok_or_else(|| {
+        Error::InvalidOperation(
+            "price has no rational approximation with denominator within i32 range".to_string(),
+        )
+    })?;
+    Ok(Price::new(n as i32, d as i32))
+}
+
+/// Validates a decoded XDR price before building a `Price` from it. The wire
+/// format allows `d == 0`, which would produce a degenerate, unusable price.
+pub(crate) fn price_from_xdr(x: &xdr::Price) -> Result<Price> {
+    if x.d.value == 0 {
+        return Err(Error::InvalidOperation(
+            "price denominator cannot be zero".to_string(),
+        ));
+    }
+    Ok(Price::new(x.n.value, x.d.value))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManageSellOfferOperation {
+    source_account: Option<MuxedAccount>,
+    selling: Asset,
+    buying: Asset,
+    amount: Stroops,
+    price: Price,
+    offer_id: i64,
+}
+
+#[derive(Debug)]
+pub struct ManageSellOfferOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    selling: Option<Asset>,
+    buying: Option<Asset>,
+    amount: Option<Stroops>,
+    price: Option<f64>,
+    offer_id: Option<i64>,
+}
+
+impl ManageSellOfferOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves a reference to the operation source account.
+    pub fn source_account_mut(&mut self) -> &mut Option<MuxedAccount> {
+        &mut self.source_account
+    }
+
+    /// Retrieves the asset being sold.
+    pub fn selling(&self) -> &Asset {
+        &self.selling
+    }
+
+    /// Retrieves a mutable reference to the asset being sold.
+    pub fn selling_mut(&mut self) -> &mut Asset {
+        &mut self.selling
+    }
+
+    /// Retrieves the asset being bought.
+    pub fn buying(&self) -> &Asset {
+        &self.buying
+    }
+
+    /// Retrieves a mutable reference to the asset being bought.
+    pub fn buying_mut(&mut self) -> &mut Asset {
+        &mut self.buying
+    }
+
+    /// Retrieves the amount of selling asset being offered (0 deletes the offer).
+    pub fn amount(&self) -> &Stroops {
+        &self.amount
+    }
+
+    /// Retrieves a mutable reference to the amount.
+    pub fn amount_mut(&mut self) -> &mut Stroops {
+        &mut self.amount
+    }
+
+    /// Retrieves the price of 1 unit of selling in terms of buying.
+    pub fn price(&self) -> &Price {
+        &self.price
+    }
+
+    /// Retrieves a mutable reference to the price.
+    pub fn price_mut(&mut self) -> &mut Price {
+        &mut self.price
+    }
+
+    /// Retrieves the offer id (0 creates a new offer).
+    pub fn offer_id(&self) -> &i64 {
+        &self.offer_id
+    }
+
+    /// Retrieves a mutable reference to the offer id.
+    pub fn offer_id_mut(&mut self) -> &mut i64 {
+        &mut self.offer_id
+    }
+
+    /// Returns the xdr operation body.
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let selling = self.selling.to_xdr_asset()?;
+        let buying = self.buying.to_xdr_asset()?;
+        let amount = xdr::Int64::new(self.amount.to_i64());
+        let price = xdr::Price {
+            n: xdr::Int32::new(self.price.n()),
+            d: xdr::Int32::new(self.price.d()),
+        };
+        let offer_id = xdr::Int64::new(self.offer_id);
+        let inner = xdr::ManageSellOfferOp {
+            selling,
+            buying,
+            amount,
+            price,
+            offer_id,
+        };
+        Ok(xdr::OperationBody::ManageSellOffer(inner))
+    }
+
+    /// Creates from the xdr operation body.
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::ManageSellOfferOp,
+    ) -> Result<ManageSellOfferOperation> {
+        let selling = Asset::from_xdr_asset(&x.selling)?;
+        let buying = Asset::from_xdr_asset(&x.buying)?;
+        let amount = Stroops::new(x.amount.value)?;
+        let price = price_from_xdr(&x.price)?;
+        let offer_id = x.offer_id.value;
+        Ok(ManageSellOfferOperation {
+            source_account,
+            selling,
+            buying,
+            amount,
+            price,
+            offer_id,
+        })
+    }
+}
+
+impl ManageSellOfferOperationBuilder {
+    pub fn new() -> ManageSellOfferOperationBuilder {
+        ManageSellOfferOperationBuilder {
+            source_account: None,
+            selling: None,
+            buying: None,
+            amount: None,
+            price: None,
+            offer_id: None,
+        }
+    }
+
+    pub fn with_source_account<S>(mut self, source: S) -> ManageSellOfferOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    pub fn with_selling(mut self, selling: Asset) -> ManageSellOfferOperationBuilder {
+        self.selling = Some(selling);
+        self
+    }
+
+    pub fn with_buying(mut self, buying: Asset) -> ManageSellOfferOperationBuilder {
+        self.buying = Some(buying);
+        self
+    }
+
+    pub fn with_amount(mut self, amount: Stroops) -> ManageSellOfferOperationBuilder {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn with_price(mut self, price: f64) -> ManageSellOfferOperationBuilder {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn with_offer_id(mut self, offer_id: i64) -> ManageSellOfferOperationBuilder {
+        self.offer_id = Some(offer_id);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let selling = self.selling.ok_or_else(|| {
+            Error::InvalidOperation("missing manage sell offer selling asset".to_string())
+        })?;
+
+        let buying = self.buying.ok_or_else(|| {
+            Error::InvalidOperation("missing manage sell offer buying asset".to_string())
+        })?;
+
+        let amount = self.amount.unwrap_or(Stroops::new(0)?);
+
+        let price = self.price.ok_or_else(|| {
+            Error::InvalidOperation("missing manage sell offer price".to_string())
+        })?;
+        let price = price_from_f64(price)?;
+
+        let offer_id = self.offer_id.unwrap_or(0);
+
+        Ok(Operation::ManageSellOffer(ManageSellOfferOperation {
+            source_account: self.source_account,
+            selling,
+            buying,
+            amount,
+            price,
+            offer_id,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{price_from_f64, ManageSellOfferOperation, ManageSellOfferOperationBuilder};
+    use crate::amount::Stroops;
+    use crate::asset::Asset;
+    use crate::crypto::KeyPair;
+    use crate::network::Network;
+    use crate::operations::Operation;
+    use crate::transaction::{Transaction, TransactionEnvelope, MIN_BASE_FEE};
+    use crate::xdr;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_price_from_f64_is_close_rational() {
+        let price = price_from_f64(0.5).unwrap();
+        assert_eq!(price.n() as f64 / price.d() as f64, 0.5);
+
+        let price = price_from_f64(3.14159).unwrap();
+        assert!((price.n() as f64 / price.d() as f64 - 3.14159).abs() < 1e-6);
+        assert!(price.d() <= i32::MAX);
+    }
+
+    #[test]
+    fn test_price_from_f64_rejects_non_positive() {
+        assert!(price_from_f64(0.0).is_err());
+        assert!(price_from_f64(-1.0).is_err());
+    }
+
+    #[test]
+    fn test_price_from_f64_rejects_value_beyond_i32_range() {
+        let err = price_from_f64(i32::MAX as f64 + 2.0);
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_manage_sell_offer_amount_zero_deletes_offer() {
+        let kp = keypair0();
+        let op = Operation::new_manage_sell_offer()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(0).unwrap())
+            .with_price(1.0)
+            .with_offer_id(5)
+            .build()
+            .unwrap();
+
+        let mut tx = Transaction::builder(kp.public_key().clone(), 3556091187167235, MIN_BASE_FEE)
+            .add_operation(op)
+            .to_transaction()
+            .unwrap();
+        tx.sign(&kp, &Network::new_test());
+        let envelope = tx.to_envelope();
+        let xdr = envelope.xdr_base64().unwrap();
+        let back = TransactionEnvelope::from_xdr_base64(&xdr).unwrap();
+        assert_eq!(envelope, back);
+    }
+
+    #[test]
+    fn test_manage_sell_offer_xdr_operation_body_round_trip() {
+        let op = ManageSellOfferOperationBuilder::new()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(1.5)
+            .with_offer_id(7)
+            .build()
+            .unwrap();
+
+        let inner = match op {
+            Operation::ManageSellOffer(inner) => inner,
+            _ => panic!("expected ManageSellOffer operation"),
+        };
+
+        let body = inner.to_xdr_operation_body().unwrap();
+        let decoded = match body {
+            xdr::OperationBody::ManageSellOffer(ref x) => {
+                ManageSellOfferOperation::from_xdr_operation_body(None, x).unwrap()
+            }
+            _ => panic!("expected ManageSellOffer operation body"),
+        };
+
+        assert_eq!(inner, decoded);
+    }
+
+    #[test]
+    fn test_manage_sell_offer_rejects_zero_denominator_price_on_decode() {
+        let price = xdr::Price {
+            n: xdr::Int32::new(1),
+            d: xdr::Int32::new(0),
+        };
+        let x = xdr::ManageSellOfferOp {
+            selling: Asset::native().to_xdr_asset().unwrap(),
+            buying: Asset::native().to_xdr_asset().unwrap(),
+            amount: xdr::Int64::new(100),
+            price,
+            offer_id: xdr::Int64::new(0),
+        };
+        assert!(ManageSellOfferOperation::from_xdr_operation_body(None, &x).is_err());
+    }
+
+    #[test]
+    fn test_manage_sell_offer_defaults_offer_id_to_zero() {
+        let op = Operation::new_manage_sell_offer()
+            .with_selling(Asset::native())
+            .with_buying(Asset::native())
+            .with_amount(Stroops::new(100).unwrap())
+            .with_price(2.5)
+            .build()
+            .unwrap();
+
+        match op {
+            Operation::ManageSellOffer(inner) => assert_eq!(*inner.offer_id(), 0),
+            _ => panic!("expected ManageSellOffer operation"),
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// The protocol caps the number of intermediate hops in a path payment at 5.
+const MAX_PATH_LENGTH: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPaymentStrictReceiveOperation {
+    source_account: Option<MuxedAccount>,
+    send_asset: Asset,
+    send_max: Stroops,
+    destination: MuxedAccount,
+    dest_asset: Asset,
+    dest_amount: Stroops,
+    path: Vec<Asset>,
+}
+
+#[derive(Debug)]
+pub struct PathPaymentStrictReceiveOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    send_asset: Option<Asset>,
+    send_max: Option<Stroops>,
+    destination: Option<MuxedAccount>,
+    dest_asset: Option<Asset>,
+    dest_amount: Option<Stroops>,
+    path: Vec<Asset>,
+}
+
+impl PathPaymentStrictReceiveOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves a reference to the operation source account.
+    pub fn source_account_mut(&mut self) -> &mut Option<MuxedAccount> {
+        &mut self.source_account
+    }
+
+    /// Retrieves the asset being sent.
+    pub fn send_asset(&self) -> &Asset {
+        &self.send_asset
+    }
+
+    /// Retrieves a mutable reference to the send asset.
+    pub fn send_asset_mut(&mut self) -> &mut Asset {
+        &mut self.send_asset
+    }
+
+    /// Retrieves the maximum amount of send asset to deduct.
+    pub fn send_max(&self) -> &Stroops {
+        &self.send_max
+    }
+
+    /// Retrieves a mutable reference to the send max.
+    pub fn send_max_mut(&mut self) -> &mut Stroops {
+        &mut self.send_max
+    }
+
+    /// Retrieves the payment destination.
+    pub fn destination(&self) -> &MuxedAccount {
+        &self.destination
+    }
+
+    /// Retrieves a mutable reference to the payment destination.
+    pub fn destination_mut(&mut self) -> &mut MuxedAccount {
+        &mut self.destination
+    }
+
+    /// Retrieves the asset the destination receives.
+    pub fn dest_asset(&self) -> &Asset {
+        &self.dest_asset
+    }
+
+    /// Retrieves a mutable reference to the destination asset.
+    pub fn dest_asset_mut(&mut self) -> &mut Asset {
+        &mut self.dest_asset
+    }
+
+    /// Retrieves the exact amount of dest asset the destination receives.
+    pub fn dest_amount(&self) -> &Stroops {
+        &self.dest_amount
+    }
+
+    /// Retrieves a mutable reference to the destination amount.
+    pub fn dest_amount_mut(&mut self) -> &mut Stroops {
+        &mut self.dest_amount
+    }
+
+    /// Retrieves the intermediate assets used to convert send asset to dest asset.
+    pub fn path(&self) -> &Vec<Asset> {
+        &self.path
+    }
+
+    /// Retrieves a mutable reference to the path.
+    pub fn path_mut(&mut self) -> &mut Vec<Asset> {
+        &mut self.path
+    }
+
+    /// Returns the xdr operation body.
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let send_asset = self.send_asset.to_xdr_asset()?;
+        let send_max = xdr::Int64::new(self.send_max.to_i64());
+        let destination = self.destination.to_xdr_muxed_account()?;
+        let dest_asset = self.dest_asset.to_xdr_asset()?;
+        let dest_amount = xdr::Int64::new(self.dest_amount.to_i64());
+        let path = self
+            .path
+            .iter()
+            .map(Asset::to_xdr_asset)
+            .collect::<Result<Vec<xdr::Asset>>>()?;
+
+        let inner = xdr::PathPaymentStrictReceiveOp {
+            send_asset,
+            send_max,
+            destination,
+            dest_asset,
+            dest_amount,
+            path,
+        };
+        Ok(xdr::OperationBody::PathPaymentStrictReceive(inner))
+    }
+
+    /// Creates from the xdr operation body.
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::PathPaymentStrictReceiveOp,
+    ) -> Result<PathPaymentStrictReceiveOperation> {
+        let send_asset = Asset::from_xdr_asset(&x.send_asset)?;
+        let send_max = Stroops::new(x.send_max.value)?;
+        let destination = MuxedAccount::from_xdr_muxed_account(&x.destination)?;
+        let dest_asset = Asset::from_xdr_asset(&x.dest_asset)?;
+        let dest_amount = Stroops::new(x.dest_amount.value)?;
+        let path = x
+            .path
+            .iter()
+            .map(Asset::from_xdr_asset)
+            .collect::<Result<Vec<Asset>>>()?;
+
+        Ok(PathPaymentStrictReceiveOperation {
+            source_account,
+            send_asset,
+            send_max,
+            destination,
+            dest_asset,
+            dest_amount,
+            path,
+        })
+    }
+}
+
+impl PathPaymentStrictReceiveOperationBuilder {
+    pub fn new() -> PathPaymentStrictReceiveOperationBuilder {
+        PathPaymentStrictReceiveOperationBuilder {
+            source_account: None,
+            send_asset: None,
+            send_max: None,
+            destination: None,
+            dest_asset: None,
+            dest_amount: None,
+            path: Vec::new(),
+        }
+    }
+
+    pub fn with_source_account<S>(mut self, source: S) -> PathPaymentStrictReceiveOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    pub fn with_send_asset(mut self, send_asset: Asset) -> PathPaymentStrictReceiveOperationBuilder {
+        self.send_asset = Some(send_asset);
+        self
+    }
+
+    pub fn with_send_max(mut self, send_max: Stroops) -> PathPaymentStrictReceiveOperationBuilder {
+        self.send_max = Some(send_max);
+        self
+    }
+
+    pub fn with_destination<S>(mut self, destination: S) -> PathPaymentStrictReceiveOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.destination = Some(destination.into());
+        self
+    }
+
+    pub fn with_dest_asset(mut self, dest_asset: Asset) -> PathPaymentStrictReceiveOperationBuilder {
+        self.dest_asset = Some(dest_asset);
+        self
+    }
+
+    pub fn with_dest_amount(
+        mut self,
+        dest_amount: Stroops,
+    ) -> PathPaymentStrictReceiveOperationBuilder {
+        self.dest_amount = Some(dest_amount);
+        self
+    }
+
+    pub fn with_path(mut self, path: Vec<Asset>) -> PathPaymentStrictReceiveOperationBuilder {
+        self.path = path;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let send_asset = self.send_asset.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment send asset".to_string())
+        })?;
+
+        let send_max = self
+            .send_max
+            .ok_or_else(|| Error::InvalidOperation("missing path payment send max".to_string()))?;
+
+        let destination = self.destination.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment destination".to_string())
+        })?;
+
+        let dest_asset = self.dest_asset.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment dest asset".to_string())
+        })?;
+
+        let dest_amount = self.dest_amount.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment dest amount".to_string())
+        })?;
+
+        if self.path.len() > MAX_PATH_LENGTH {
+            return Err(Error::InvalidOperation(format!(
+                "path payment path cannot exceed {} assets",
+                MAX_PATH_LENGTH
+            )));
+        }
+
+        Ok(Operation::PathPaymentStrictReceive(
+            PathPaymentStrictReceiveOperation {
+                source_account: self.source_account,
+                send_asset,
+                send_max,
+                destination,
+                dest_asset,
+                dest_amount,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathPaymentStrictReceiveOperation, PathPaymentStrictReceiveOperationBuilder};
+    use crate::amount::Stroops;
+    use crate::asset::Asset;
+    use crate::crypto::KeyPair;
+    use crate::operations::Operation;
+    use crate::xdr;
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_path_payment_strict_receive_xdr_operation_body_round_trip() {
+        let op = PathPaymentStrictReceiveOperationBuilder::new()
+            .with_send_asset(Asset::native())
+            .with_send_max(Stroops::new(100).unwrap())
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::native())
+            .with_dest_amount(Stroops::new(90).unwrap())
+            .with_path(vec![Asset::native()])
+            .build()
+            .unwrap();
+
+        let inner = match op {
+            Operation::PathPaymentStrictReceive(inner) => inner,
+            _ => panic!("expected PathPaymentStrictReceive operation"),
+        };
+
+        let body = inner.to_xdr_operation_body().unwrap();
+        let decoded = match body {
+            xdr::OperationBody::PathPaymentStrictReceive(ref x) => {
+                PathPaymentStrictReceiveOperation::from_xdr_operation_body(None, x).unwrap()
+            }
+            _ => panic!("expected PathPaymentStrictReceive operation body"),
+        };
+
+        assert_eq!(inner, decoded);
+    }
+
+    #[test]
+    fn test_path_payment_strict_receive_rejects_too_long_path() {
+        let path = vec![Asset::native(); 6];
+        let err = Operation::new_path_payment_strict_receive()
+            .with_send_asset(Asset::native())
+            .with_send_max(Stroops::new(100).unwrap())
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::native())
+            .with_dest_amount(Stroops::new(100).unwrap())
+            .with_path(path)
+            .build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_path_payment_strict_receive_builds() {
+        let op = Operation::new_path_payment_strict_receive()
+            .with_send_asset(Asset::native())
+            .with_send_max(Stroops::new(100).unwrap())
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::native())
+            .with_dest_amount(Stroops::new(90).unwrap())
+            .with_path(vec![Asset::native()])
+            .build()
+            .unwrap();
+
+        match op {
+            Operation::PathPaymentStrictReceive(inner) => assert_eq!(inner.path().len(), 1),
+            _ => panic!("expected PathPaymentStrictReceive operation"),
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// The protocol caps the number of intermediate hops in a path payment at 5.
+const MAX_PATH_LENGTH: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPaymentStrictSendOperation {
+    source_account: Option<MuxedAccount>,
+    send_asset: Asset,
+    send_amount: Stroops,
+    destination: MuxedAccount,
+    dest_asset: Asset,
+    dest_min: Stroops,
+    path: Vec<Asset>,
+}
+
+#[derive(Debug)]
+pub struct PathPaymentStrictSendOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    send_asset: Option<Asset>,
+    send_amount: Option<Stroops>,
+    destination: Option<MuxedAccount>,
+    dest_asset: Option<Asset>,
+    dest_min: Option<Stroops>,
+    path: Vec<Asset>,
+}
+
+impl PathPaymentStrictSendOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves a reference to the operation source account.
+    pub fn source_account_mut(&mut self) -> &mut Option<MuxedAccount> {
+        &mut self.source_account
+    }
+
+    /// Retrieves the asset being sent.
+    pub fn send_asset(&self) -> &Asset {
+        &self.send_asset
+    }
+
+    /// Retrieves a mutable reference to the send asset.
+    pub fn send_asset_mut(&mut self) -> &mut Asset {
+        &mut self.send_asset
+    }
+
+    /// Retrieves the exact amount of send asset to deduct.
+    pub fn send_amount(&self) -> &Stroops {
+        &self.send_amount
+    }
+
+    /// Retrieves a mutable reference to the send amount.
+    pub fn send_amount_mut(&mut self) -> &mut Stroops {
+        &mut self.send_amount
+    }
+
+    /// Retrieves the payment destination.
+    pub fn destination(&self) -> &MuxedAccount {
+        &self.destination
+    }
+
+    /// Retrieves a mutable reference to the payment destination.
+    pub fn destination_mut(&mut self) -> &mut MuxedAccount {
+        &mut self.destination
+    }
+
+    /// Retrieves the asset the destination receives.
+    pub fn dest_asset(&self) -> &Asset {
+        &self.dest_asset
+    }
+
+    /// Retrieves a mutable reference to the destination asset.
+    pub fn dest_asset_mut(&mut self) -> &mut Asset {
+        &mut self.dest_asset
+    }
+
+    /// Retrieves the minimum amount of dest asset the destination must receive.
+    pub fn dest_min(&self) -> &Stroops {
+        &self.dest_min
+    }
+
+    /// Retrieves a mutable reference to the destination minimum.
+    pub fn dest_min_mut(&mut self) -> &mut Stroops {
+        &mut self.dest_min
+    }
+
+    /// Retrieves the intermediate assets used to convert send asset to dest asset.
+    pub fn path(&self) -> &Vec<Asset> {
+        &self.path
+    }
+
+    /// Retrieves a mutable reference to the path.
+    pub fn path_mut(&mut self) -> &mut Vec<Asset> {
+        &mut self.path
+    }
+
+    /// Returns the xdr operation body.
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let send_asset = self.send_asset.to_xdr_asset()?;
+        let send_amount = xdr::Int64::new(self.send_amount.to_i64());
+        let destination = self.destination.to_xdr_muxed_account()?;
+        let dest_asset = self.dest_asset.to_xdr_asset()?;
+        let dest_min = xdr::Int64::new(self.dest_min.to_i64());
+        let path = self
+            .path
+            .iter()
+            .map(Asset::to_xdr_asset)
+            .collect::<Result<Vec<xdr::Asset>>>()?;
+
+        let inner = xdr::PathPaymentStrictSendOp {
+            send_asset,
+            send_amount,
+            destination,
+            dest_asset,
+            dest_min,
+            path,
+        };
+        Ok(xdr::OperationBody::PathPaymentStrictSend(inner))
+    }
+
+    /// Creates from the xdr operation body.
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::PathPaymentStrictSendOp,
+    ) -> Result<PathPaymentStrictSendOperation> {
+        let send_asset = Asset::from_xdr_asset(&x.send_asset)?;
+        let send_amount = Stroops::new(x.send_amount.value)?;
+        let destination = MuxedAccount::from_xdr_muxed_account(&x.destination)?;
+        let dest_asset = Asset::from_xdr_asset(&x.dest_asset)?;
+        let dest_min = Stroops::new(x.dest_min.value)?;
+        let path = x
+            .path
+            .iter()
+            .map(Asset::from_xdr_asset)
+            .collect::<Result<Vec<Asset>>>()?;
+
+        Ok(PathPaymentStrictSendOperation {
+            source_account,
+            send_asset,
+            send_amount,
+            destination,
+            dest_asset,
+            dest_min,
+            path,
+        })
+    }
+}
+
+impl PathPaymentStrictSendOperationBuilder {
+    pub fn new() -> PathPaymentStrictSendOperationBuilder {
+        PathPaymentStrictSendOperationBuilder {
+            source_account: None,
+            send_asset: None,
+            send_amount: None,
+            destination: None,
+            dest_asset: None,
+            dest_min: None,
+            path: Vec::new(),
+        }
+    }
+
+    pub fn with_source_account<S>(mut self, source: S) -> PathPaymentStrictSendOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    pub fn with_send_asset(mut self, send_asset: Asset) -> PathPaymentStrictSendOperationBuilder {
+        self.send_asset = Some(send_asset);
+        self
+    }
+
+    pub fn with_send_amount(
+        mut self,
+        send_amount: Stroops,
+    ) -> PathPaymentStrictSendOperationBuilder {
+        self.send_amount = Some(send_amount);
+        self
+    }
+
+    pub fn with_destination<S>(mut self, destination: S) -> PathPaymentStrictSendOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.destination = Some(destination.into());
+        self
+    }
+
+    pub fn with_dest_asset(mut self, dest_asset: Asset) -> PathPaymentStrictSendOperationBuilder {
+        self.dest_asset = Some(dest_asset);
+        self
+    }
+
+    pub fn with_dest_min(mut self, dest_min: Stroops) -> PathPaymentStrictSendOperationBuilder {
+        self.dest_min = Some(dest_min);
+        self
+    }
+
+    pub fn with_path(mut self, path: Vec<Asset>) -> PathPaymentStrictSendOperationBuilder {
+        self.path = path;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let send_asset = self.send_asset.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment send asset".to_string())
+        })?;
+
+        let send_amount = self.send_amount.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment send amount".to_string())
+        })?;
+
+        let destination = self.destination.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment destination".to_string())
+        })?;
+
+        let dest_asset = self.dest_asset.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment dest asset".to_string())
+        })?;
+
+        let dest_min = self.dest_min.ok_or_else(|| {
+            Error::InvalidOperation("missing path payment dest min".to_string())
+        })?;
+
+        if self.path.len() > MAX_PATH_LENGTH {
+            return Err(Error::InvalidOperation(format!(
+                "path payment path cannot exceed {} assets",
+                MAX_PATH_LENGTH
+            )));
+        }
+
+        Ok(Operation::PathPaymentStrictSend(
+            PathPaymentStrictSendOperation {
+                source_account: self.source_account,
+                send_asset,
+                send_amount,
+                destination,
+                dest_asset,
+                dest_min,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathPaymentStrictSendOperation, PathPaymentStrictSendOperationBuilder};
+    use crate::amount::Stroops;
+    use crate::asset::Asset;
+    use crate::crypto::KeyPair;
+    use crate::operations::Operation;
+    use crate::xdr;
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_path_payment_strict_send_xdr_operation_body_round_trip() {
+        let op = PathPaymentStrictSendOperationBuilder::new()
+            .with_send_asset(Asset::native())
+            .with_send_amount(Stroops::new(100).unwrap())
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::native())
+            .with_dest_min(Stroops::new(90).unwrap())
+            .with_path(vec![Asset::native()])
+            .build()
+            .unwrap();
+
+        let inner = match op {
+            Operation::PathPaymentStrictSend(inner) => inner,
+            _ => panic!("expected PathPaymentStrictSend operation"),
+        };
+
+        let body = inner.to_xdr_operation_body().unwrap();
+        let decoded = match body {
+            xdr::OperationBody::PathPaymentStrictSend(ref x) => {
+                PathPaymentStrictSendOperation::from_xdr_operation_body(None, x).unwrap()
+            }
+            _ => panic!("expected PathPaymentStrictSend operation body"),
+        };
+
+        assert_eq!(inner, decoded);
+    }
+
+    #[test]
+    fn test_path_payment_strict_send_rejects_too_long_path() {
+        let path = vec![Asset::native(); 6];
+        let err = Operation::new_path_payment_strict_send()
+            .with_send_asset(Asset::native())
+            .with_send_amount(Stroops::new(100).unwrap())
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::native())
+            .with_dest_min(Stroops::new(90).unwrap())
+            .with_path(path)
+            .build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_path_payment_strict_send_builds() {
+        let op = Operation::new_path_payment_strict_send()
+            .with_send_asset(Asset::native())
+            .with_send_amount(Stroops::new(100).unwrap())
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::native())
+            .with_dest_min(Stroops::new(90).unwrap())
+            .with_path(vec![Asset::native()])
+            .build()
+            .unwrap();
+
+        match op {
+            Operation::PathPaymentStrictSend(inner) => assert_eq!(inner.path().len(), 1),
+            _ => panic!("expected PathPaymentStrictSend operation"),
+        }
+    }
+}
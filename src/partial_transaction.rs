@@ -0,0 +1,417 @@
+use crate::crypto::{DecoratedSignature, KeyPair, SignerKey};
+use crate::error::{Error, Result};
+use crate::network::Network;
+use crate::transaction::{Transaction, TransactionEnvelope};
+use std::collections::BTreeMap;
+
+/// Optional bookkeeping a coordinator can attach while collecting
+/// signatures, separate from anything encoded on the ledger account itself.
+/// Weight is tracked per signer (keyed by signature hint), since different
+/// parties on a threshold account can carry different weights.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SignerMeta {
+    required_weights: BTreeMap<[u8; 4], u32>,
+}
+
+impl SignerMeta {
+    /// Retrieves the weight `signer`'s signature is expected to carry, if
+    /// one was recorded for it.
+    pub fn required_weight_for(&self, signer: &SignerKey) -> Option<u32> {
+        self.required_weights.get(&signer.hint()).copied()
+    }
+
+    /// Records the weight `signer`'s signature should carry.
+    pub fn set_required_weight(&mut self, signer: &SignerKey, weight: u32) {
+        self.required_weights.insert(signer.hint(), weight);
+    }
+
+    pub fn with_required_weight(mut self, signer: &SignerKey, weight: u32) -> SignerMeta {
+        self.set_required_weight(signer, weight);
+        self
+    }
+}
+
+/// A Stellar `Transaction` plus the set of `DecoratedSignature`s collected
+/// for it so far, serializable so several parties can pass it around and
+/// each add their own signature before it is submitted. Mirrors the
+/// Creator/Updater/Signer/Combiner roles of a PSBT workflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialTransaction {
+    transaction: Transaction,
+    signatures: Vec<DecoratedSignature>,
+    meta: SignerMeta,
+}
+
+impl PartialTransaction {
+    /// Starts a partial transaction coordination from a (possibly already
+    /// partially signed) transaction. Any signatures already baked into
+    /// `transaction` are lifted into the tracked signature set, so the
+    /// representation is consistent whether constructed directly or via
+    /// `from_xdr_base64`.
+    pub fn from_transaction(transaction: Transaction) -> PartialTransaction {
+        let signatures = transaction.to_envelope().signatures().to_vec();
+        let mut unsigned = transaction;
+        unsigned.clear_signatures();
+
+        PartialTransaction {
+            transaction: unsigned,
+            signatures,
+            meta: SignerMeta::default(),
+        }
+    }
+
+    /// Retrieves the underlying transaction.
+    pub fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    /// Retrieves the signatures collected so far.
+    pub fn signatures(&self) -> &[DecoratedSignature] {
+        &self.signatures
+    }
+
+    /// Retrieves the coordinator metadata attached to this session.
+    pub fn meta(&self) -> &SignerMeta {
+        &self.meta
+    }
+
+    /// Retrieves a mutable reference to the coordinator metadata.
+    pub fn meta_mut(&mut self) -> &mut SignerMeta {
+        &mut self.meta
+    }
+
+    /// Signer role: adds one signature from `keypair`, without discarding
+    /// any signatures already accumulated from other parties. If a signature
+    /// with the same hint is already present, nothing is added.
+    pub fn sign(&mut self, keypair: &KeyPair, network: &Network) -> Result<()> {
+        let mut scratch = self.transaction.clone();
+        scratch.sign(keypair, network);
+        let envelope = scratch.to_envelope();
+        let new_signature = envelope
+            .signatures()
+            .last()
+            .cloned()
+            .ok_or_else(|| Error::InvalidOperation("signing produced no signature".to_string()))?;
+        self.push_signature(new_signature);
+        Ok(())
+    }
+
+    /// Combiner role: unions `other`'s signature set into this one,
+    /// deduplicating by signature hint. Both partial transactions must wrap
+    /// the same underlying transaction.
+    pub fn merge(&mut self, other: &PartialTransaction) -> Result<()> {
+        if self.transaction != other.transaction {
+            return Err(Error::InvalidOperation(
+                "cannot merge signatures collected for a different transaction".to_string(),
+            ));
+        }
+
+        for signature in other.signatures() {
+            self.push_signature(signature.clone());
+        }
+
+        Ok(())
+    }
+
+    fn push_signature(&mut self, signature: DecoratedSignature) {
+        let already_present = self
+            .signatures
+            .iter()
+            .any(|existing| existing.hint() == signature.hint());
+        if !already_present {
+            self.signatures.push(signature);
+        }
+    }
+
+    /// Returns the signers from `signers` that have not yet contributed a
+    /// signature, stopping once enough of `signers` have signed to meet
+    /// `threshold`. Only signatures whose hint matches one of `signers`
+    /// count toward the threshold; unrelated accumulated signatures (e.g.
+    /// from a blob padded by another party) are ignored. Each matching
+    /// signer contributes its own `meta().required_weight_for(signer)`
+    /// (default 1 if unset).
+    pub fn missing_signers(&self, signers: &[SignerKey], threshold: u32) -> Vec<SignerKey> {
+        let has_signed = |signer: &SignerKey| {
+            self.signatures
+                .iter()
+                .any(|signature| signature.hint() == signer.hint())
+        };
+
+        let satisfied_weight: u32 = signers
+            .iter()
+            .filter(|signer| has_signed(signer))
+            .map(|signer| self.meta.required_weight_for(signer).unwrap_or(1))
+            .sum();
+
+        if satisfied_weight >= threshold {
+            return Vec::new();
+        }
+
+        signers
+            .iter()
+            .filter(|signer| !has_signed(signer))
+            .cloned()
+            .collect()
+    }
+
+    /// Finalizes the coordination, producing a normal `TransactionEnvelope`
+    /// carrying every signature collected so far.
+    pub fn finalize(&self) -> Result<TransactionEnvelope> {
+        let mut tx = self.transaction.clone();
+        for signature in &self.signatures {
+            tx.add_decorated_signature(signature.clone());
+        }
+        Ok(tx.to_envelope())
+    }
+
+    /// Serializes the transaction, its accumulated signatures, and its
+    /// per-signer coordinator metadata so the blob can be handed to the next
+    /// signer. The envelope itself carries only the transaction and
+    /// signatures, per the XDR format, so the required-weight map is
+    /// prefixed alongside it (`hint:weight` pairs, comma-separated) rather
+    /// than silently dropped.
+    pub fn to_xdr_base64(&self) -> Result<String> {
+        let envelope_xdr = self.finalize()?.xdr_base64()?;
+        let meta = self
+            .meta
+            .required_weights
+            .iter()
+            .map(|(hint, weight)| format!("{}:{}", hex_encode(hint), weight))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(format!("{}|{}", meta, envelope_xdr))
+    }
+
+    /// Reconstructs a `PartialTransaction` from a blob produced by
+    /// `to_xdr_base64`, restoring every recorded `required_weight_for`.
+    pub fn from_xdr_base64(blob: &str) -> Result<PartialTransaction> {
+        let (meta, envelope_xdr) = blob.split_once('|').ok_or_else(|| {
+            Error::InvalidOperation("malformed partial transaction blob".to_string())
+        })?;
+
+        let mut required_weights = BTreeMap::new();
+        if !meta.is_empty() {
+            for entry in meta.split(',') {
+                let (hint_hex, weight) = entry.split_once(':').ok_or_else(|| {
+                    Error::InvalidOperation(
+                        "malformed partial transaction meta entry".to_string(),
+                    )
+                })?;
+                let hint = hex_decode_hint(hint_hex)?;
+                let weight = weight.parse::<u32>().map_err(|_| {
+                    Error::InvalidOperation(
+                        "invalid partial transaction signer weight".to_string(),
+                    )
+                })?;
+                required_weights.insert(hint, weight);
+            }
+        }
+
+        let envelope = TransactionEnvelope::from_xdr_base64(envelope_xdr)?;
+        let transaction = envelope.transaction().clone();
+        let signatures = envelope.signatures().to_vec();
+        let mut unsigned = transaction.clone();
+        unsigned.clear_signatures();
+
+        Ok(PartialTransaction {
+            transaction: unsigned,
+            signatures,
+            meta: SignerMeta { required_weights },
+        })
+    }
+}
+
+fn hex_encode(bytes: &[u8; 4]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hex_decode_hint(s: &str) -> Result<[u8; 4]> {
+    if s.len() != 8 {
+        return Err(Error::InvalidOperation(
+            "invalid partial transaction signer hint".to_string(),
+        ));
+    }
+
+    let mut hint = [0u8; 4];
+    for i in 0..4 {
+        hint[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| {
+            Error::InvalidOperation("invalid partial transaction signer hint".to_string())
+        })?;
+    }
+    Ok(hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::operations::Operation;
+    use crate::transaction::MIN_BASE_FEE;
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    fn keypair2() -> KeyPair {
+        // GB7BDSZU2Y27LYNLALKKALB52WS2IZWYBDGY6EQBLEED3TJOCVMZRH7H
+        KeyPair::from_secret_seed("SBZVMB74Z76QZ3ZOY7UTDFYKMEGKW5XFJEB6PFKBF4UYSSWHG4EDH7PY")
+            .unwrap()
+    }
+
+    fn unsigned_transaction(kp: &KeyPair) -> Transaction {
+        let op = Operation::new_bump_sequence().with_bump_to(1).build().unwrap();
+        Transaction::builder(kp.public_key().clone(), 3556091187167235, MIN_BASE_FEE)
+            .add_operation(op)
+            .to_transaction()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sign_accumulates_without_discarding() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut partial = PartialTransaction::from_transaction(unsigned_transaction(&source));
+
+        partial.sign(&keypair1(), &network).unwrap();
+        assert_eq!(partial.signatures().len(), 1);
+
+        partial.sign(&keypair2(), &network).unwrap();
+        assert_eq!(partial.signatures().len(), 2);
+    }
+
+    #[test]
+    fn test_sign_is_idempotent_for_the_same_keypair() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut partial = PartialTransaction::from_transaction(unsigned_transaction(&source));
+
+        partial.sign(&keypair1(), &network).unwrap();
+        partial.sign(&keypair1(), &network).unwrap();
+        assert_eq!(partial.signatures().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_dedupes_by_hint() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut a = PartialTransaction::from_transaction(unsigned_transaction(&source));
+        let mut b = PartialTransaction::from_transaction(unsigned_transaction(&source));
+
+        a.sign(&keypair1(), &network).unwrap();
+        b.sign(&keypair1(), &network).unwrap();
+        b.sign(&keypair2(), &network).unwrap();
+
+        a.merge(&b).unwrap();
+        assert_eq!(a.signatures().len(), 2);
+    }
+
+    #[test]
+    fn test_merge_rejects_different_transaction() {
+        let network = Network::new_test();
+        let mut a = PartialTransaction::from_transaction(unsigned_transaction(&keypair0()));
+        let mut b = PartialTransaction::from_transaction(unsigned_transaction(&keypair1()));
+
+        a.sign(&keypair1(), &network).unwrap();
+        b.sign(&keypair1(), &network).unwrap();
+
+        assert!(a.merge(&b).is_err());
+    }
+
+    #[test]
+    fn test_missing_signers_ignores_unrelated_signatures() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut partial = PartialTransaction::from_transaction(unsigned_transaction(&source));
+
+        // An accumulated signature from a party that isn't in `signers`
+        // must not count toward the threshold.
+        partial.sign(&keypair0(), &network).unwrap();
+
+        let signers = vec![
+            SignerKey::from(keypair1().public_key().clone()),
+            SignerKey::from(keypair2().public_key().clone()),
+        ];
+
+        let missing = partial.missing_signers(&signers, 1);
+        assert_eq!(missing.len(), 2);
+    }
+
+    #[test]
+    fn test_missing_signers_clears_once_threshold_met() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut partial = PartialTransaction::from_transaction(unsigned_transaction(&source));
+        partial.sign(&keypair1(), &network).unwrap();
+
+        let signers = vec![
+            SignerKey::from(keypair1().public_key().clone()),
+            SignerKey::from(keypair2().public_key().clone()),
+        ];
+
+        assert_eq!(partial.missing_signers(&signers, 1), Vec::new());
+        assert_eq!(
+            partial.missing_signers(&signers, 2),
+            vec![SignerKey::from(keypair2().public_key().clone())]
+        );
+    }
+
+    #[test]
+    fn test_xdr_base64_round_trip_preserves_signatures_and_meta() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut partial = PartialTransaction::from_transaction(unsigned_transaction(&source));
+        partial.sign(&keypair1(), &network).unwrap();
+
+        let signer1 = SignerKey::from(keypair1().public_key().clone());
+        partial.meta_mut().set_required_weight(&signer1, 2);
+
+        let blob = partial.to_xdr_base64().unwrap();
+        let back = PartialTransaction::from_xdr_base64(&blob).unwrap();
+
+        assert_eq!(back.signatures().len(), 1);
+        assert_eq!(back.meta().required_weight_for(&signer1), Some(2));
+        assert_eq!(partial.finalize().unwrap(), back.finalize().unwrap());
+    }
+
+    #[test]
+    fn test_from_transaction_lifts_existing_signatures() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut tx = unsigned_transaction(&source);
+        tx.sign(&keypair1(), &network);
+
+        let partial = PartialTransaction::from_transaction(tx);
+        assert_eq!(partial.signatures().len(), 1);
+
+        let signers = vec![SignerKey::from(keypair1().public_key().clone())];
+        assert_eq!(partial.missing_signers(&signers, 1), Vec::new());
+    }
+
+    #[test]
+    fn test_missing_signers_uses_per_signer_weight() {
+        let source = keypair0();
+        let network = Network::new_test();
+        let mut partial = PartialTransaction::from_transaction(unsigned_transaction(&source));
+        partial.sign(&keypair1(), &network).unwrap();
+
+        let signer1 = SignerKey::from(keypair1().public_key().clone());
+        let signer2 = SignerKey::from(keypair2().public_key().clone());
+        partial.meta_mut().set_required_weight(&signer1, 3);
+        partial.meta_mut().set_required_weight(&signer2, 1);
+
+        let signers = vec![signer1, signer2.clone()];
+
+        // signer1 alone carries weight 3, already meeting a threshold of 3.
+        assert_eq!(partial.missing_signers(&signers, 3), Vec::new());
+        // A higher threshold still needs signer2.
+        assert_eq!(partial.missing_signers(&signers, 4), vec![signer2]);
+    }
+}
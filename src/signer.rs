@@ -0,0 +1,93 @@
+use crate::crypto::{sha256, DecoratedSignature, KeyPair, PublicKey, Signature};
+use crate::error::Result;
+use crate::network::Network;
+use crate::transaction::Transaction;
+use crate::xdr::{self, XDRSerialize};
+
+/// An external signer for a transaction's signing payload. Implementing this
+/// instead of calling `Transaction::sign` directly lets a hardware wallet or
+/// a remote KMS hold the secret seed, so it never enters process memory.
+pub trait Signer {
+    /// Signs `payload` (see `Transaction::signature_base`) and returns the
+    /// raw 64-byte ed25519 signature.
+    fn sign_hash(&self, payload: &[u8; 32]) -> Result<Signature>;
+
+    /// Retrieves the public key whose hint decorates the returned signature.
+    fn public_key(&self) -> &PublicKey;
+}
+
+impl Signer for KeyPair {
+    fn sign_hash(&self, payload: &[u8; 32]) -> Result<Signature> {
+        Ok(self.sign(payload))
+    }
+
+    fn public_key(&self) -> &PublicKey {
+        KeyPair::public_key(self)
+    }
+}
+
+impl Transaction {
+    /// Computes the signing payload for this transaction under `network`:
+    /// the network id followed by the hash of the tagged transaction, as
+    /// defined by the Stellar signature scheme, already hashed. `sign_with`
+    /// signs this value as is and appends the resulting `DecoratedSignature`.
+    pub fn signature_base(&self, network: &Network) -> Result<[u8; 32]> {
+        let tagged_transaction =
+            xdr::TransactionSignaturePayloadTaggedTransaction::Tx(self.to_xdr_transaction()?);
+        let payload = xdr::TransactionSignaturePayload {
+            network_id: xdr::Hash::new(network.network_id()),
+            tagged_transaction,
+        };
+        Ok(sha256(&payload.xdr()?))
+    }
+
+    /// Signs with an external `Signer` (e.g. a hardware wallet or remote
+    /// KMS), adding one `DecoratedSignature` without discarding any already
+    /// collected, and without ever requiring the secret seed in this
+    /// process.
+    pub fn sign_with<S: Signer>(&mut self, signer: &S, network: &Network) -> Result<()> {
+        let payload = self.signature_base(network)?;
+        let signature = signer.sign_hash(&payload)?;
+        let hint = signer.public_key().signature_hint();
+        self.add_decorated_signature(DecoratedSignature::new(hint, signature));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::Operation;
+    use crate::transaction::MIN_BASE_FEE;
+    use crate::xdr::XDRSerialize;
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    fn unsigned_transaction(kp: &KeyPair) -> Transaction {
+        let op = Operation::new_bump_sequence().with_bump_to(1).build().unwrap();
+        Transaction::builder(kp.public_key().clone(), 3556091187167235, MIN_BASE_FEE)
+            .add_operation(op)
+            .to_transaction()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sign_with_matches_built_in_sign() {
+        let kp = keypair0();
+        let network = Network::new_test();
+
+        let mut via_sign = unsigned_transaction(&kp);
+        via_sign.sign(&kp, &network);
+
+        let mut via_sign_with = unsigned_transaction(&kp);
+        via_sign_with.sign_with(&kp, &network).unwrap();
+
+        let via_sign_xdr = via_sign.to_envelope().xdr_base64().unwrap();
+        let via_sign_with_xdr = via_sign_with.to_envelope().xdr_base64().unwrap();
+        assert_eq!(via_sign_xdr, via_sign_with_xdr);
+    }
+}